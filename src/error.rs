@@ -0,0 +1,37 @@
+//! Error types returned by this crate.
+
+use core::fmt;
+
+/// Errors that can occur when encoding or decoding a [`crate::YamfHash`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The output buffer was too small to hold the encoded hash.
+    EncodeError,
+    /// Writing the encoded hash to a `Write` implementor failed.
+    EncodeWriteError,
+    /// There were not enough bytes to decode a hash, or the declared size did not match
+    /// the digest length of the decoded algorithm.
+    DecodeError {},
+    /// The leading varu64 could not be decoded.
+    DecodeVaru64Error,
+    /// The decoded numeric algorithm id does not match a known variant.
+    UnknownAlgorithm,
+    /// A string could not be parsed as a `YamfHash` (bad base58, wrong length, or unknown id).
+    ParseError,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::EncodeError => write!(f, "not enough space to encode yamf hash"),
+            Error::EncodeWriteError => write!(f, "failed to write encoded yamf hash"),
+            Error::DecodeError {} => write!(f, "not enough bytes or wrong declared size to decode yamf hash"),
+            Error::DecodeVaru64Error => write!(f, "unable to decode varu64 from bytes"),
+            Error::UnknownAlgorithm => write!(f, "unknown yamf hash algorithm id"),
+            Error::ParseError => write!(f, "unable to parse yamf hash from string"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -17,7 +17,12 @@ pub mod error;
 #[cfg(feature = "std")]
 use crate::util::hex_serde::{hex_from_bytes, vec_from_hex};
 use arrayvec::ArrayVec;
+// `Blake2b512` is blake2's fixed-output-size alias (`Blake2b<U64>`); the bare `Blake2b` type
+// became generic over output size in blake2 0.10, so pin to the 64 byte variant explicitly.
+pub use blake2::Blake2b512;
 pub use blake3::{hash as blake3, Hash as Blake3Hash, OUT_LEN as BLAKE3_OUT_LEN};
+use blake2::Digest;
+use constant_time_eq::constant_time_eq;
 use core::borrow::Borrow;
 use core::iter::FromIterator;
 
@@ -33,12 +38,20 @@ const_assert_eq!(blake3_hash_size; BLAKE3_HASH_SIZE, BLAKE3_OUT_LEN);
 
 pub const BLAKE3_NUMERIC_ID: u64 = 0;
 
+/// The original YAMF hash algorithm: Blake2b with a 64 byte digest.
+pub const BLAKE2B_HASH_SIZE: usize = 64;
+
+pub const BLAKE2B_NUMERIC_ID: u64 = 1;
+
 /// The maximum number of bytes this will use for any variant.
 ///
-/// This is a bit yuck because it knows the number of bytes varu64 uses to encode the
-/// Blake3 hash size and the blake3 numeric id (2).
-/// This is unlikely to cause a problem until there are hundreds of variants.
-pub const MAX_YAMF_HASH_SIZE: usize = BLAKE3_HASH_SIZE + 2;
+/// This is the max over all variants of the numeric id + size varu64 (2 bytes, which is
+/// unlikely to change until there are hundreds of variants) plus the largest digest length.
+pub const MAX_YAMF_HASH_SIZE: usize = if BLAKE3_HASH_SIZE > BLAKE2B_HASH_SIZE {
+    BLAKE3_HASH_SIZE + 2
+} else {
+    BLAKE2B_HASH_SIZE + 2
+};
 
 /// Variants of `YamfHash`
 #[derive(Deserialize, Serialize, Debug, Eq)]
@@ -49,12 +62,20 @@ pub enum YamfHash<T: Borrow<[u8]>> {
     )]
     #[cfg_attr(feature = "std", serde(bound(deserialize = "T: From<Vec<u8>>")))]
     Blake3(T),
+    #[cfg_attr(
+        feature = "std",
+        serde(serialize_with = "hex_from_bytes", deserialize_with = "vec_from_hex")
+    )]
+    #[cfg_attr(feature = "std", serde(bound(deserialize = "T: From<Vec<u8>>")))]
+    Blake2b(T),
 }
 
 impl<B1: Borrow<[u8]>, B2: Borrow<[u8]>> PartialEq<YamfHash<B1>> for YamfHash<B2> {
     fn eq(&self, other: &YamfHash<B1>) -> bool {
         match (self, other) {
             (YamfHash::Blake3(vec), YamfHash::Blake3(vec2)) => vec.borrow() == vec2.borrow(),
+            (YamfHash::Blake2b(vec), YamfHash::Blake2b(vec2)) => vec.borrow() == vec2.borrow(),
+            _ => false,
         }
     }
 }
@@ -68,10 +89,75 @@ pub fn new_blake3(bytes: &[u8]) -> YamfHash<ArrayVec<[u8; BLAKE3_HASH_SIZE]>> {
     YamfHash::Blake3(vec_bytes)
 }
 
+pub fn new_blake2b(bytes: &[u8]) -> YamfHash<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>> {
+    let mut hasher = Blake2b512::new();
+    hasher.update(bytes);
+    let hash_bytes = hasher.finalize();
+
+    let vec_bytes: ArrayVec<[u8; BLAKE2B_HASH_SIZE]> =
+        ArrayVec::from_iter(hash_bytes.iter().map(|b| *b));
+
+    YamfHash::Blake2b(vec_bytes)
+}
+
+/// An incremental Blake3 hasher, for hashing payloads that arrive as multiple chunks.
+///
+/// Feed bytes in with one or more calls to [`update`](YamfHasher::update), then call
+/// [`finalize`](YamfHasher::finalize) to get the resulting `YamfHash`.
+pub struct YamfHasher(blake3::Hasher);
+
+impl YamfHasher {
+    /// Create a new, empty hasher.
+    pub fn new() -> Self {
+        YamfHasher(blake3::Hasher::new())
+    }
+
+    /// Feed more bytes into the hasher.
+    pub fn update(&mut self, bytes: &[u8]) -> &mut Self {
+        self.0.update(bytes);
+        self
+    }
+
+    /// Finalize the hasher, consuming everything fed in so far.
+    pub fn finalize(&self) -> YamfHash<ArrayVec<[u8; BLAKE3_HASH_SIZE]>> {
+        let hash_bytes = self.0.finalize();
+
+        let vec_bytes: ArrayVec<[u8; BLAKE3_HASH_SIZE]> =
+            ArrayVec::from_iter(hash_bytes.as_bytes().iter().map(|b| *b));
+
+        YamfHash::Blake3(vec_bytes)
+    }
+}
+
+impl Default for YamfHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hash a slice of byte slices, in order, without concatenating them first.
+pub fn hashv(slices: &[&[u8]]) -> YamfHash<ArrayVec<[u8; BLAKE3_HASH_SIZE]>> {
+    let mut hasher = YamfHasher::new();
+    for slice in slices {
+        hasher.update(slice);
+    }
+    hasher.finalize()
+}
+
 impl<'a> From<&'a YamfHash<ArrayVec<[u8; BLAKE3_HASH_SIZE]>>> for YamfHash<&'a [u8]> {
     fn from(hash: &YamfHash<ArrayVec<[u8; BLAKE3_HASH_SIZE]>>) -> YamfHash<&[u8]> {
         match hash {
             YamfHash::Blake3(bytes) => YamfHash::Blake3(&bytes[..]),
+            YamfHash::Blake2b(bytes) => YamfHash::Blake2b(&bytes[..]),
+        }
+    }
+}
+
+impl<'a> From<&'a YamfHash<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>> for YamfHash<&'a [u8]> {
+    fn from(hash: &YamfHash<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>) -> YamfHash<&[u8]> {
+        match hash {
+            YamfHash::Blake3(bytes) => YamfHash::Blake3(&bytes[..]),
+            YamfHash::Blake2b(bytes) => YamfHash::Blake2b(&bytes[..]),
         }
     }
 }
@@ -86,73 +172,215 @@ impl<'a> From<Blake3Hash> for YamfHash<ArrayVec<[u8; BLAKE3_HASH_SIZE]>> {
     }
 }
 impl<T: Borrow<[u8]>> YamfHash<T> {
+    fn numeric_id_and_hash_size(&self) -> (u64, usize) {
+        match self {
+            YamfHash::Blake3(_) => (BLAKE3_NUMERIC_ID, BLAKE3_HASH_SIZE),
+            YamfHash::Blake2b(_) => (BLAKE2B_NUMERIC_ID, BLAKE2B_HASH_SIZE),
+        }
+    }
+
+    fn bytes(&self) -> &T {
+        match self {
+            YamfHash::Blake3(vec) => vec,
+            YamfHash::Blake2b(vec) => vec,
+        }
+    }
+
+    /// Copy a borrowed, decoded hash into an owned `ArrayVec` backed variant.
+    fn to_owned_arrayvec(hash: YamfHash<&[u8]>) -> YamfHash<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>> {
+        match hash {
+            YamfHash::Blake3(slice) => {
+                let mut vec = ArrayVec::new();
+                vec.try_extend_from_slice(slice).unwrap();
+                YamfHash::Blake3(vec)
+            }
+            YamfHash::Blake2b(slice) => {
+                let mut vec = ArrayVec::new();
+                vec.try_extend_from_slice(slice).unwrap();
+                YamfHash::Blake2b(vec)
+            }
+        }
+    }
+
     /// Encode a YamfHash into the out buffer.
     pub fn encode(&self, out: &mut [u8]) -> Result<usize, Error> {
         let encoded_size = self.encoding_length();
 
-        match (self, out.len()) {
-            (YamfHash::Blake3(vec), len) if len >= encoded_size => {
-                varu64_encode(BLAKE3_NUMERIC_ID, &mut out[0..1]);
-                varu64_encode(BLAKE3_HASH_SIZE as u64, &mut out[1..2]);
-                out[2..encoded_size].copy_from_slice(vec.borrow());
-                Ok(encoded_size)
-            }
-            _ => Err(Error::EncodeError),
+        if out.len() < encoded_size {
+            return Err(Error::EncodeError);
         }
+
+        let (numeric_id, hash_size) = self.numeric_id_and_hash_size();
+        let id_len = encoding_length(numeric_id);
+        let size_len = encoding_length(hash_size as u64);
+
+        varu64_encode(numeric_id, &mut out[0..id_len]);
+        varu64_encode(hash_size as u64, &mut out[id_len..id_len + size_len]);
+        out[id_len + size_len..encoded_size].copy_from_slice(self.bytes().borrow());
+        Ok(encoded_size)
     }
 
     pub fn encoding_length(&self) -> usize {
-        match self {
-            YamfHash::Blake3(_) => {
-                encoding_length(BLAKE3_NUMERIC_ID)
-                    + encoding_length(BLAKE3_HASH_SIZE as u64)
-                    + BLAKE3_HASH_SIZE
+        let (numeric_id, hash_size) = self.numeric_id_and_hash_size();
+        encoding_length(numeric_id) + encoding_length(hash_size as u64) + hash_size
+    }
+
+    /// Compare two hashes in constant time. Hashes of different algorithms are never equal.
+    pub fn ct_eq<B: Borrow<[u8]>>(&self, other: &YamfHash<B>) -> bool {
+        match (self, other) {
+            (YamfHash::Blake3(a), YamfHash::Blake3(b)) => {
+                constant_time_eq(a.borrow(), b.borrow())
             }
+            (YamfHash::Blake2b(a), YamfHash::Blake2b(b)) => {
+                constant_time_eq(a.borrow(), b.borrow())
+            }
+            _ => false,
+        }
+    }
+
+    /// Hash `bytes` with this hash's own algorithm and compare the result in constant time.
+    ///
+    /// This is the primary way to check that some content matches a content-address.
+    pub fn verify(&self, bytes: &[u8]) -> bool {
+        match self {
+            YamfHash::Blake3(_) => self.ct_eq(&new_blake3(bytes)),
+            YamfHash::Blake2b(_) => self.ct_eq(&new_blake2b(bytes)),
         }
     }
 
     /// Decode the `bytes` as a `YamfHash`
     pub fn decode<'a>(bytes: &'a [u8]) -> Result<(YamfHash<&'a [u8]>, &'a [u8]), Error> {
-        match varu64_decode(&bytes) {
-            Ok((BLAKE3_NUMERIC_ID, remaining_bytes)) if remaining_bytes.len() >= 33 => {
-                let hash = &remaining_bytes[1..33];
-                Ok((YamfHash::Blake3(hash), &remaining_bytes[33..]))
-            }
-            Err((_, _)) => Err(Error::DecodeVaru64Error),
-            _ => Err(Error::DecodeError {}),
+        let (numeric_id, remaining_bytes) = varu64_decode(bytes).map_err(|_| Error::DecodeVaru64Error)?;
+        let (hash_size, remaining_bytes) =
+            varu64_decode(remaining_bytes).map_err(|_| Error::DecodeVaru64Error)?;
+
+        // The declared size must match the canonical digest length for the algorithm -
+        // otherwise a malicious peer could claim a short algorithm id with an oversized
+        // size field and smuggle arbitrary padding through as a "hash". Compare as u64,
+        // before truncating to usize, so an oversized value can't wrap around and pass.
+        let expected_size = match numeric_id {
+            BLAKE3_NUMERIC_ID => BLAKE3_HASH_SIZE,
+            BLAKE2B_NUMERIC_ID => BLAKE2B_HASH_SIZE,
+            _ => return Err(Error::UnknownAlgorithm),
+        };
+
+        if hash_size != expected_size as u64 {
+            return Err(Error::DecodeError {});
+        }
+        let hash_size = hash_size as usize;
+
+        if remaining_bytes.len() < hash_size {
+            return Err(Error::DecodeError {});
+        }
+
+        let hash = &remaining_bytes[0..hash_size];
+        let remaining_bytes = &remaining_bytes[hash_size..];
+
+        match numeric_id {
+            BLAKE3_NUMERIC_ID => Ok((YamfHash::Blake3(hash), remaining_bytes)),
+            BLAKE2B_NUMERIC_ID => Ok((YamfHash::Blake2b(hash), remaining_bytes)),
+            _ => unreachable!("numeric_id was already validated above"),
         }
     }
 
     /// Decode the `bytes` as a `YamfHash`
     pub fn decode_owned<'a>(
         bytes: &'a [u8],
-    ) -> Result<(YamfHash<ArrayVec<[u8; BLAKE3_HASH_SIZE]>>, &'a [u8]), Error> {
-        match varu64_decode(&bytes) {
-            Ok((BLAKE3_NUMERIC_ID, remaining_bytes)) if remaining_bytes.len() >= 33 => {
-                let mut vec = ArrayVec::new();
-                let slice = &remaining_bytes[1..33];
-                vec.try_extend_from_slice(slice).unwrap();
-                Ok((YamfHash::Blake3(vec), &remaining_bytes[33..]))
-            }
-            Err((_, _)) => Err(Error::DecodeVaru64Error),
-            _ => Err(Error::DecodeError {}),
-        }
+    ) -> Result<(YamfHash<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>, &'a [u8]), Error> {
+        let (hash, remaining_bytes) = Self::decode(bytes)?;
+        Ok((Self::to_owned_arrayvec(hash), remaining_bytes))
     }
 
     /// Encode a YamfHash into the writer.
     #[cfg(feature = "std")]
     pub fn encode_write<W: Write>(&self, mut w: W) -> Result<(), Error> {
+        let (numeric_id, hash_size) = self.numeric_id_and_hash_size();
+        let id_len = encoding_length(numeric_id);
+        let size_len = encoding_length(hash_size as u64);
+
         let mut out = [0; 2];
-        match self {
-            YamfHash::Blake3(vec) => {
-                varu64_encode(BLAKE3_NUMERIC_ID, &mut out[0..1]);
-                varu64_encode(BLAKE3_HASH_SIZE as u64, &mut out[1..2]);
-                w.write_all(&out).map_err(|_| Error::EncodeWriteError)?;
-                w.write_all(vec.borrow())
-                    .map_err(|_| Error::EncodeWriteError)?;
-                Ok(())
+        varu64_encode(numeric_id, &mut out[0..id_len]);
+        varu64_encode(hash_size as u64, &mut out[id_len..id_len + size_len]);
+        w.write_all(&out[0..id_len + size_len])
+            .map_err(|_| Error::EncodeWriteError)?;
+        w.write_all(self.bytes().borrow())
+            .map_err(|_| Error::EncodeWriteError)?;
+        Ok(())
+    }
+
+    /// Encode a YamfHash into a `bytes::BufMut`, advancing it past the written bytes.
+    #[cfg(feature = "bytes")]
+    pub fn encode_buf<B: bytes::BufMut>(&self, buf: &mut B) -> Result<(), Error> {
+        let mut out = [0; MAX_YAMF_HASH_SIZE];
+        let len = self.encode(&mut out)?;
+        buf.put_slice(&out[0..len]);
+        Ok(())
+    }
+
+    /// Decode a `YamfHash` from a `bytes::Buf`, consuming only the bytes it reads and
+    /// leaving any trailing bytes in `buf` for the next frame.
+    ///
+    /// `Buf::chunk` only exposes the first contiguous chunk of the buffer, so this pulls
+    /// bytes one at a time (which works across chunk/rope boundaries) into a scratch
+    /// buffer until a full encoding has been read.
+    #[cfg(feature = "bytes")]
+    pub fn decode_buf<B: bytes::Buf>(
+        buf: &mut B,
+    ) -> Result<YamfHash<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>, Error> {
+        let mut scratch = [0u8; MAX_YAMF_HASH_SIZE];
+        let mut len = 0;
+
+        let hash = loop {
+            match Self::decode(&scratch[0..len]) {
+                Ok((hash, _)) => break hash,
+                Err(Error::DecodeError {}) | Err(Error::DecodeVaru64Error) => {}
+                Err(e) => return Err(e),
             }
-        }
+
+            if len == scratch.len() || !buf.has_remaining() {
+                return Err(Error::DecodeError {});
+            }
+            scratch[len] = buf.get_u8();
+            len += 1;
+        };
+
+        Ok(Self::to_owned_arrayvec(hash))
+    }
+
+    /// Base58-encode the full self-describing encoding (numeric id + size + digest).
+    #[cfg(feature = "std")]
+    pub fn to_base58(&self) -> String {
+        let mut out = [0; MAX_YAMF_HASH_SIZE];
+        let len = self
+            .encode(&mut out)
+            .expect("MAX_YAMF_HASH_SIZE is always big enough to encode any variant");
+        bs58::encode(&out[0..len]).into_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Borrow<[u8]>> core::fmt::Display for YamfHash<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.to_base58())
+    }
+}
+
+#[cfg(feature = "std")]
+impl YamfHash<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>> {
+    /// Parse a base58-encoded self-describing encoding, as produced by [`to_base58`](YamfHash::to_base58).
+    pub fn from_base58(s: &str) -> Result<Self, Error> {
+        let bytes = bs58::decode(s).into_vec().map_err(|_| Error::ParseError)?;
+        let (hash, _) = Self::decode_owned(&bytes).map_err(|_| Error::ParseError)?;
+        Ok(hash)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::str::FromStr for YamfHash<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_base58(s)
     }
 }
 
@@ -160,7 +388,11 @@ impl<T: Borrow<[u8]>> YamfHash<T> {
 mod tests {
     use crate::MAX_YAMF_HASH_SIZE;
 
-    use super::{new_blake3, blake3, Error, YamfHash, BLAKE3_HASH_SIZE};
+    use super::{
+        hashv, new_blake2b, new_blake3, blake3, Error, YamfHash, YamfHasher, BLAKE2B_HASH_SIZE,
+        BLAKE3_HASH_SIZE,
+    };
+    use core::str::FromStr;
     use arrayvec::ArrayVec;
     use core::iter::FromIterator;
 
@@ -171,7 +403,7 @@ mod tests {
 
         let mut encoded = vec![0; MAX_YAMF_HASH_SIZE];
         let length = yamf_hash.encode(&mut encoded).unwrap();
-        assert_eq!(length, MAX_YAMF_HASH_SIZE);
+        assert_eq!(length, BLAKE3_HASH_SIZE + 2);
         assert_eq!(encoded[0], 0);
         assert_eq!(encoded[1], 32);
     }
@@ -252,6 +484,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decode_yamf_unknown_algorithm_error() {
+        let mut hash_bytes = vec![0xFF; 34];
+        hash_bytes[0] = 2; // not BLAKE3_NUMERIC_ID or BLAKE2B_NUMERIC_ID
+        hash_bytes[1] = 32;
+        let result = YamfHash::<&[u8]>::decode(&hash_bytes);
+
+        match result {
+            Err(Error::UnknownAlgorithm) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn decode_yamf_size_mismatch_error() {
+        // Claims BLAKE3_NUMERIC_ID (a 32 byte digest) with an oversized declared size.
+        let mut hash_bytes = vec![0xFF; 102];
+        hash_bytes[0] = 0;
+        hash_bytes[1] = 100;
+        let result = YamfHash::<&[u8]>::decode(&hash_bytes);
+
+        match result {
+            Err(Error::DecodeError {}) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn decode_owned_size_mismatch_does_not_panic() {
+        let mut hash_bytes = vec![0xFF; 102];
+        hash_bytes[0] = 0;
+        hash_bytes[1] = 100;
+        let result = YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::decode_owned(&hash_bytes);
+
+        match result {
+            Err(Error::DecodeError {}) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn serde_json_roundtrip_blake3() {
+        let yamf_hash = new_blake3(&[1, 2, 3]);
+
+        let json = serde_json::to_string(&yamf_hash).unwrap();
+        let decoded: YamfHash<Vec<u8>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, yamf_hash);
+    }
+
+    #[test]
+    fn serde_json_roundtrip_blake2b() {
+        let yamf_hash = new_blake2b(&[1, 2, 3]);
+
+        let json = serde_json::to_string(&yamf_hash).unwrap();
+        let decoded: YamfHash<Vec<u8>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, yamf_hash);
+    }
+
     #[test]
     fn blake_yamf_hash() {
         let lam = || {
@@ -322,6 +614,168 @@ mod tests {
         assert_eq!(decoded, yamf_hash);
     }
 
+    #[test]
+    fn encode_decode_blake2b_roundtrip() {
+        let bytes = vec![1, 2, 3];
+        let yamf_hash = new_blake2b(&bytes);
+        assert_eq!(yamf_hash.encoding_length(), BLAKE2B_HASH_SIZE + 2);
+
+        let mut encoded = Vec::new();
+        yamf_hash.encode_write(&mut encoded).unwrap();
+
+        let (decoded, _) = YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::decode_owned(&encoded).unwrap();
+
+        assert_eq!(decoded, yamf_hash);
+    }
+
+    #[test]
+    fn yamf_hasher_matches_one_shot() {
+        let bytes = vec![1, 2, 3, 4, 5];
+
+        let mut hasher = YamfHasher::new();
+        hasher.update(&bytes[0..2]).update(&bytes[2..]);
+
+        assert_eq!(hasher.finalize(), new_blake3(&bytes));
+    }
+
+    #[test]
+    fn hashv_matches_concatenated_one_shot() {
+        let a = vec![1, 2];
+        let b = vec![3, 4, 5];
+        let concatenated: Vec<u8> = a.iter().chain(b.iter()).copied().collect();
+
+        assert_eq!(hashv(&[&a, &b]), new_blake3(&concatenated));
+    }
+
+    #[test]
+    fn base58_roundtrip() {
+        let bytes = vec![1, 2, 3];
+        let yamf_hash = new_blake3(&bytes);
+
+        let encoded = yamf_hash.to_base58();
+        let decoded = YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::from_base58(&encoded).unwrap();
+
+        assert_eq!(decoded, yamf_hash);
+    }
+
+    #[test]
+    fn base58_roundtrip_blake2b() {
+        let bytes = vec![1, 2, 3];
+        let yamf_hash = new_blake2b(&bytes);
+
+        let encoded = format!("{}", yamf_hash);
+        let decoded = YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::from_str(&encoded).unwrap();
+
+        assert_eq!(decoded, yamf_hash);
+    }
+
+    #[test]
+    fn base58_from_str_invalid_alphabet() {
+        match YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::from_str("not-valid-base58!!!") {
+            Err(Error::ParseError) => {}
+            _ => panic!("expected a ParseError"),
+        }
+    }
+
+    #[test]
+    fn base58_from_str_wrong_length() {
+        let encoded = bs58::encode(&[0u8, 32]).into_string();
+        match YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::from_base58(&encoded) {
+            Err(Error::ParseError) => {}
+            _ => panic!("expected a ParseError"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn encode_buf_decode_buf_roundtrip() {
+        let bytes = vec![1, 2, 3];
+        let yamf_hash = new_blake3(&bytes);
+
+        let mut buf = bytes::BytesMut::new();
+        yamf_hash.encode_buf(&mut buf).unwrap();
+
+        let decoded = YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::decode_buf(&mut buf).unwrap();
+
+        assert_eq!(decoded, yamf_hash);
+        assert_eq!(buf.len(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn decode_buf_handles_non_contiguous_chunks() {
+        use bytes::Buf;
+
+        let bytes = vec![1, 2, 3];
+        let yamf_hash = new_blake3(&bytes);
+
+        let mut encoded = bytes::BytesMut::new();
+        yamf_hash.encode_buf(&mut encoded).unwrap();
+        let encoded = encoded.freeze();
+
+        // Split the encoding across two chunks so `Buf::chunk()` alone can't see all of it.
+        let split_at = encoded.len() / 2;
+        let mut buf = encoded.slice(0..split_at).chain(encoded.slice(split_at..));
+
+        let decoded = YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::decode_buf(&mut buf).unwrap();
+
+        assert_eq!(decoded, yamf_hash);
+        assert_eq!(buf.remaining(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn decode_buf_leaves_trailing_bytes() {
+        let bytes = vec![1, 2, 3];
+        let yamf_hash = new_blake2b(&bytes);
+
+        let mut buf = bytes::BytesMut::new();
+        yamf_hash.encode_buf(&mut buf).unwrap();
+        buf.extend_from_slice(&[0xAA, 0xBB]);
+
+        let decoded = YamfHash::<ArrayVec<[u8; BLAKE2B_HASH_SIZE]>>::decode_buf(&mut buf).unwrap();
+
+        assert_eq!(decoded, yamf_hash);
+        assert_eq!(&buf[..], &[0xAA, 0xBB]);
+    }
+
+    #[test]
+    fn verify_accepts_matching_content() {
+        let bytes = vec![1, 2, 3];
+        assert!(new_blake3(&bytes).verify(&bytes));
+        assert!(new_blake2b(&bytes).verify(&bytes));
+    }
+
+    #[test]
+    fn verify_rejects_mismatched_content() {
+        let bytes = vec![1, 2, 3];
+        let other_bytes = vec![4, 5, 6];
+        assert!(!new_blake3(&bytes).verify(&other_bytes));
+        assert!(!new_blake2b(&bytes).verify(&other_bytes));
+    }
+
+    #[test]
+    fn ct_eq_matches_partial_eq() {
+        let bytes = vec![1, 2, 3];
+        let a = new_blake3(&bytes);
+        let b = new_blake3(&bytes);
+        let c = new_blake2b(&bytes);
+
+        assert!(a.ct_eq(&b));
+        assert!(!a.ct_eq(&c));
+        assert_eq!(a.ct_eq(&b), a == b);
+        assert_eq!(a.ct_eq(&c), a == c);
+    }
+
+    #[test]
+    fn blake2b_and_blake3_are_never_equal() {
+        let bytes = vec![1, 2, 3];
+        let blake3_hash = new_blake3(&bytes);
+        let blake2b_hash = new_blake2b(&bytes);
+
+        assert_ne!(blake3_hash, blake2b_hash);
+    }
+
     #[test]
     fn encode_decode_blake3() {
         let bytes = vec![1, 2, 3];
@@ -0,0 +1,20 @@
+//! serde helpers for encoding byte slices as hex strings.
+
+use core::borrow::Borrow;
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub fn hex_from_bytes<T: Borrow<[u8]>, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&hex::encode(bytes.borrow()))
+}
+
+pub fn vec_from_hex<'de, D, T: From<Vec<u8>>>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    let bytes = hex::decode(&s).map_err(serde::de::Error::custom)?;
+    Ok(T::from(bytes))
+}
@@ -0,0 +1,2 @@
+#[cfg(feature = "std")]
+pub mod hex_serde;